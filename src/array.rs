@@ -0,0 +1,193 @@
+/// A wrapper for a fixed-size array of references.
+///
+/// Available only through a (possibly mutable) reference, just like
+/// [`Slice`](struct.Slice.html), of which `Array` is the const-generic,
+/// arity-known companion: `Slice<T>` forgets its length, `Pair<A, B>` is
+/// stuck at arity two, `Array<T, N>` sits in between, remembering an
+/// arbitrary but statically known length `N`.
+///
+/// Can be created from (a (possibly mutable) reference to) an array
+/// of (possibly mutable) references by means of the `From` trait or
+/// with the help of [`new`](#method.new) and [`new_mut`](#method.new_mut)
+/// functions.
+///
+/// ```
+/// # use multiref::Array;
+/// let (mut a, mut b, mut c) = (1, 2, 3);
+/// let mut array = [&mut a, &mut b, &mut c];
+/// let wrapped = Array::new_mut(&mut array);
+///
+/// *wrapped.as_mut()[0] = 4;
+///
+/// wrapped.modify(|real_array| { *real_array[1] = 5; });
+///
+/// // the wrapped references can also be consumed one by one,
+/// // without ever materializing the whole `[&mut T; N]`:
+/// for r in wrapped.into_iter() {
+///     *r += 10;
+/// }
+///
+/// assert!(a == 14);
+/// assert!(b == 15);
+/// assert!(c == 13);
+/// ```
+///
+/// ## Drawbacks
+///
+/// Just like [`Pair`](struct.Pair.html), `Array<T, N>` must be unmovable:
+/// otherwise `std::mem::swap`'d through two differently-scoped `&mut
+/// Array`s would reproduce the exact use-after-drop unsoundness documented
+/// in [`Pair`'s "Drawbacks" section](struct.Pair.html#drawbacks). For the
+/// same reason, `Array<T, N>` is a DST, encoding `&Array<T, N>` as a fat
+/// pointer (to a slice of length 1), even though `N` is already known at
+/// compile time.
+#[repr(transparent)]
+pub struct Array<T: ?Sized, const N: usize> {
+    _array: [[*const T; N]],
+}
+
+impl<'a, 'x: 'a, T, const N: usize> From<&'a [&'x T; N]> for &'a Array<T, N> where
+    T: ?Sized,
+{
+    fn from(array: &'a [&'x T; N]) -> Self {
+        unsafe { &*(core::slice::from_raw_parts(array, 1) as *const _ as *const _) }
+    }
+}
+
+impl<'a, 'x: 'a, T, const N: usize> From<&'a mut [&'x mut T; N]> for &'a mut Array<T, N> where
+    T: ?Sized,
+{
+    fn from(array: &'a mut [&'x mut T; N]) -> Self {
+        unsafe { &mut *(core::slice::from_raw_parts_mut(array, 1) as *mut _ as *mut _) }
+    }
+}
+
+impl<'a, T: ?Sized, const N: usize> Array<T, N> {
+    /// The same as `array.into()`.
+    pub fn new<'x: 'a>(array: &'a [&'x T; N]) -> &'a Self {
+        array.into()
+    }
+
+    /// The original array.
+    pub fn as_ref(&'a self) -> &'a [&'a T; N] {
+        unsafe { &*(self as *const _ as *const _) }
+    }
+
+    /// The same as `array.into()`.
+    pub fn new_mut<'x: 'a>(array: &'a mut [&'x mut T; N]) -> &'a mut Self {
+        array.into()
+    }
+
+    /// The original array, mutable version.
+    pub fn as_mut(&'a mut self) -> &'a mut [&'a mut T; N] {
+        unsafe { &mut *(self as *mut _ as *mut _) }
+    }
+
+    /// Provides an access to the underlying array of references via CPS.
+    pub fn modify<R, F>(&'a mut self, f: F) -> R where
+        F: FnOnce(&'a mut [&'a mut T; N]) -> R
+    {
+        f(self.as_mut())
+    }
+
+    /// Reinterprets the wrapped references as a [`Slice`](struct.Slice.html)
+    /// of unknown length, so that `Array` can delegate to `Slice`'s
+    /// iterators instead of duplicating them.
+    fn as_slice(&'a self) -> &'a crate::slice::Slice<T> {
+        let data = self as *const Self as *const *const T;
+        unsafe { &*(core::slice::from_raw_parts(data, N) as *const [*const T] as *const _) }
+    }
+
+    /// The mutable version of [`as_slice`](#method.as_slice).
+    fn as_slice_mut(&'a mut self) -> &'a mut crate::slice::Slice<T> {
+        let data = self as *mut Self as *mut *const T;
+        unsafe { &mut *(core::slice::from_raw_parts_mut(data, N) as *mut [*const T] as *mut _) }
+    }
+}
+
+impl<'a, T: ?Sized, const N: usize> IntoIterator for &'a Array<T, N> {
+    type Item = &'a T;
+    type IntoIter = IntoIter<'a, T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self.as_slice().iter())
+    }
+}
+
+impl<'a, T: ?Sized, const N: usize> IntoIterator for &'a mut Array<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = IntoIterMut<'a, T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIterMut(self.as_slice_mut().iter_mut())
+    }
+}
+
+/// A by-value-reference consuming iterator over a `&Array<T, N>`,
+/// analogous to `core::array::IntoIter`, yielding exactly `N` items.
+///
+/// A thin wrapper around the iterator returned by
+/// [`Slice::iter`](struct.Slice.html#method.iter) that keeps `N` at the
+/// type level instead of reimplementing the same unsafe code a second time.
+///
+/// Created by calling `.into_iter()` on a `&Array<T, N>`.
+pub struct IntoIter<'a, T: ?Sized, const N: usize>(crate::slice::Iter<'a, T>);
+
+impl<'a, T: ?Sized, const N: usize> Iterator for IntoIter<'a, T, N> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a, T: ?Sized, const N: usize> DoubleEndedIterator for IntoIter<'a, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back()
+    }
+}
+
+impl<'a, T: ?Sized, const N: usize> ExactSizeIterator for IntoIter<'a, T, N> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+/// A by-value-reference consuming iterator over a `&mut Array<T, N>`,
+/// analogous to `core::array::IntoIter`, yielding exactly `N` items.
+///
+/// A thin wrapper around the iterator returned by
+/// [`Slice::iter_mut`](struct.Slice.html#method.iter_mut) that keeps `N`
+/// at the type level instead of reimplementing the same unsafe code a
+/// second time.
+///
+/// Created by calling `.into_iter()` on a `&mut Array<T, N>`.
+pub struct IntoIterMut<'a, T: ?Sized, const N: usize>(crate::slice::IterMut<'a, T>);
+
+impl<'a, T: ?Sized, const N: usize> Iterator for IntoIterMut<'a, T, N> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a, T: ?Sized, const N: usize> DoubleEndedIterator for IntoIterMut<'a, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back()
+    }
+}
+
+impl<'a, T: ?Sized, const N: usize> ExactSizeIterator for IntoIterMut<'a, T, N> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
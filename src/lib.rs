@@ -7,23 +7,25 @@
 //! able to &#8220;algebraically&#8221; extract a common lifetime 
 //! from a bunch of references.
 //!
-//! This crate provides two helper types 
-//! `Slice` and [`Pair`](struct.Pair.html) 
+//! This crate provides three helper types
+//! `Slice`, [`Pair`](struct.Pair.html) and [`Array`](struct.Array.html)
 //! that allow the following conversions:
 //!
 //! * `&'a [&'x T] -> &'a Slice<T>` (and a mutable equivalent)
 //! * `&'a (&'x A, &'x B) -> &'a Pair<A, B>` (and a mutable equivalent)
+//! * `&'a [&'x T; N] -> &'a Array<T, N>` (and a mutable equivalent)
 //!
-//! Moreover, each of these types provides `.as_ref()` and `.as_mut()` 
-//! methods (with signatures different from the ones used by the `AsRef` and 
+//! Moreover, each of these types provides `.as_ref()` and `.as_mut()`
+//! methods (with signatures different from the ones used by the `AsRef` and
 //! `AsMut` traits) implementing the forward distributive law:
 //!
 //! * `&'a Slice<T> -> &'a [&'a T]` (and a mutable equivalent)
 //! * `&'a Pair<A, B> -> &'a (&'a A, &'a B)` (and a mutable equivalent)
+//! * `&'a Array<T, N> -> &'a [&'a T; N]` (and a mutable equivalent)
 //!
-// //! Also there is a macro `declare_named_tuple!` that introduces 
-// //! a user-defined helper type which allows to name 
-// //! the individual wrapped references.
+//! Also there is a macro [`declare_named_tuple!`](macro.declare_named_tuple.html)
+//! that introduces a user-defined helper type which allows to name
+//! the individual wrapped references, for tuples of arity higher than two.
 //!
 //! ## Motivation
 //!
@@ -159,6 +161,9 @@
 mod slice;
 mod pair;
 mod named_tuple;
+mod array;
 
 pub use pair::Pair;
+pub use slice::Slice;
+pub use array::Array;
 
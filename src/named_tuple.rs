@@ -0,0 +1,148 @@
+//! A solution facilitating working with more than two values.
+//!
+//! [`Pair`](../struct.Pair.html) only goes up to arity two (longer tuples
+//! have to be built by nesting `Pair`s inside each other, which gets
+//! clumsy fast). The [`declare_named_tuple!`](../macro.declare_named_tuple.html)
+//! macro generates a flat, named N-ary equivalent of `Pair` instead.
+
+/// Declares a `#[repr(transparent)]` DST wrapper, analogous to
+/// [`Pair`](struct.Pair.html), over a user-named tuple of references of
+/// arbitrary arity.
+///
+/// For each field `name(name_mut): Type` this generates an accessor
+/// `name(&'a self) -> &'a Type` and a mutable accessor
+/// `name_mut(&'a mut self) -> &'a mut Type`, together with `From`,
+/// [`new`]/[`new_mut`], [`as_ref`]/[`as_mut`] (implementing the forward
+/// distributive law back to the plain tuple) and [`modify`], exactly as on
+/// `Pair`.
+///
+/// [`new`]: struct.Pair.html#method.new
+/// [`new_mut`]: struct.Pair.html#method.new_mut
+/// [`as_ref`]: struct.Pair.html#method.as_ref
+/// [`as_mut`]: struct.Pair.html#method.as_mut
+/// [`modify`]: struct.Pair.html#method.modify
+///
+/// ```
+/// use multiref::declare_named_tuple;
+///
+/// declare_named_tuple! {
+///     pub struct Triple {
+///         pub fst(fst_mut): A,
+///         pub snd(snd_mut): B,
+///         pub trd(trd_mut): C,
+///     }
+/// }
+///
+/// let (mut a, mut b, mut c) = (1, 2, 3);
+/// let mut tuple = (&mut a, &mut b, &mut c);
+/// let triple = Triple::new_mut(&mut tuple);
+///
+/// *triple.fst_mut() = 4;
+/// *triple.snd_mut() = 5;
+///
+/// // flat, named, no nesting required:
+/// triple.modify(|(_, _, trd)| { **trd = 6; });
+///
+/// assert!(*triple.fst() == 4);
+/// assert!(*triple.snd() == 5);
+/// assert!(*triple.trd() == 6);
+/// ```
+#[macro_export]
+macro_rules! declare_named_tuple {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $($fvis:vis $field:ident ( $field_mut:ident ) : $ty:ident),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[repr(transparent)]
+        $vis struct $name<$($ty: ?Sized),+> {
+            _tuple: [($(*const $ty),+,)],
+        }
+
+        impl<'a, 'x: 'a, $($ty: ?Sized),+>
+            ::core::convert::From<&'a ($(&'x $ty),+,)> for &'a $name<$($ty),+>
+        {
+            fn from(tuple: &'a ($(&'x $ty),+,)) -> Self {
+                unsafe { &*(::core::slice::from_raw_parts(tuple, 1) as *const _ as *const _) }
+            }
+        }
+
+        impl<'a, 'x: 'a, $($ty: ?Sized),+>
+            ::core::convert::From<&'a mut ($(&'x mut $ty),+,)> for &'a mut $name<$($ty),+>
+        {
+            fn from(tuple: &'a mut ($(&'x mut $ty),+,)) -> Self {
+                unsafe { &mut *(::core::slice::from_raw_parts_mut(tuple, 1) as *mut _ as *mut _) }
+            }
+        }
+
+        impl<'a, $($ty: ?Sized),+> $name<$($ty),+> {
+            /// The same as `tuple.into()`.
+            $vis fn new<'x: 'a>(tuple: &'a ($(&'x $ty),+,)) -> &'a Self {
+                tuple.into()
+            }
+
+            /// The same as `tuple.into()`.
+            $vis fn new_mut<'x: 'a>(tuple: &'a mut ($(&'x mut $ty),+,)) -> &'a mut Self {
+                tuple.into()
+            }
+
+            /// All the components at once, as the original tuple.
+            $vis fn as_ref(&'a self) -> &'a ($(&'a $ty),+,) {
+                unsafe { &*(self as *const _ as *const _) }
+            }
+
+            /// All the components at once, as the original tuple, mutable version.
+            $vis fn as_mut(&'a mut self) -> &'a mut ($(&'a mut $ty),+,) {
+                unsafe { &mut *(self as *mut _ as *mut _) }
+            }
+
+            /// Provides an access to the underlying tuple of references via CPS.
+            $vis fn modify<R, F>(&'a mut self, f: F) -> R where
+                F: FnOnce(&'a mut ($(&'a mut $ty),+,)) -> R
+            {
+                f(self.as_mut())
+            }
+        }
+
+        $crate::__declare_named_tuple_accessors! {
+            [$($field),+]; [$($ty),+]; $name;
+            $($fvis $field ($field_mut) : $ty),+
+        }
+    };
+}
+
+/// Recursive helper generating the per-field accessors for
+/// [`declare_named_tuple!`](macro.declare_named_tuple.html). Not part of
+/// the public API.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __declare_named_tuple_accessors {
+    ([$($all_field:ident),+]; [$($all_ty:ident),+]; $name:ident; ) => {};
+
+    (
+        [$($all_field:ident),+]; [$($all_ty:ident),+]; $name:ident;
+        $fvis:vis $field:ident ( $field_mut:ident ) : $ty:ident
+        $(, $rfvis:vis $rfield:ident ( $rfield_mut:ident ) : $rty:ident)* $(,)?
+    ) => {
+        impl<'a, $($all_ty: ?Sized),+> $name<$($all_ty),+> {
+            /// This component.
+            $fvis fn $field(&'a self) -> &'a $ty {
+                let ($($all_field),+,) = self._tuple[0];
+                unsafe { &*$field }
+            }
+
+            /// This component, mutable version.
+            $fvis fn $field_mut(&'a mut self) -> &'a mut $ty {
+                let ($($all_field),+,) = self._tuple[0];
+                unsafe { &mut *($field as *mut $ty) }
+            }
+        }
+
+        $crate::__declare_named_tuple_accessors! {
+            [$($all_field),+]; [$($all_ty),+]; $name;
+            $($rfvis $rfield ( $rfield_mut ) : $rty),*
+        }
+    };
+}
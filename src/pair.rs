@@ -31,8 +31,34 @@
 /// assert!(c == 4);
 /// ```
 ///
-/// A solution facilitating working with more than two values is due to appear 
-/// in one of the next versions of the crate.
+/// A solution facilitating working with more than two values is provided
+/// by the [`declare_named_tuple!`](macro.declare_named_tuple.html) macro.
+///
+/// `Pair<A, B>` also implements `PartialEq`/`Eq`/`PartialOrd`/`Ord`/`Hash`
+/// (whenever `A` and `B` do), comparing and hashing by the values its
+/// components point to rather than by address. This makes `&Pair<A, B>`
+/// usable as a `BTreeMap`/`HashMap` key, and orders pairs with equal first
+/// components by their second component:
+///
+/// ```
+/// # use multiref::Pair;
+/// # use std::collections::HashMap;
+/// let (a, b) = (1, 2);
+/// let (a2, b2) = (1, 3); // same first component as `a`, different second
+/// let ab = (&a, &b);
+/// let a2b2 = (&a2, &b2);
+/// let pair1 = Pair::new(&ab);
+/// let pair2 = Pair::new(&a2b2);
+///
+/// // the first components tie, so ordering falls through to the second:
+/// assert!(pair1 < pair2);
+///
+/// let mut scores = HashMap::new();
+/// scores.insert(pair1, 10);
+/// scores.insert(pair2, 20);
+/// assert!(scores[pair1] == 10);
+/// assert!(scores[pair2] == 20);
+/// ```
 ///
 /// ## Drawbacks
 ///
@@ -97,6 +123,42 @@ impl<'a, 'x: 'a, A, B> From<&'a mut (&'x mut A, &'x mut B)> for &'a mut Pair<A,
 }
 
 
+impl<A: ?Sized + PartialEq, B: ?Sized + PartialEq> PartialEq for Pair<A, B> {
+    /// Compares the pair by the values its components point to.
+    fn eq(&self, other: &Self) -> bool {
+        self.fst() == other.fst() && self.snd() == other.snd()
+    }
+}
+
+impl<A: ?Sized + Eq, B: ?Sized + Eq> Eq for Pair<A, B> {}
+
+impl<A: ?Sized + PartialOrd, B: ?Sized + PartialOrd> PartialOrd for Pair<A, B> {
+    /// Orders the pair lexicographically, first by the pointed-to first
+    /// component, then by the pointed-to second component.
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        match self.fst().partial_cmp(other.fst()) {
+            Some(core::cmp::Ordering::Equal) => self.snd().partial_cmp(other.snd()),
+            ord => ord,
+        }
+    }
+}
+
+impl<A: ?Sized + Ord, B: ?Sized + Ord> Ord for Pair<A, B> {
+    /// Orders the pair lexicographically, first by the pointed-to first
+    /// component, then by the pointed-to second component.
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.fst().cmp(other.fst()).then_with(|| self.snd().cmp(other.snd()))
+    }
+}
+
+impl<A: ?Sized + core::hash::Hash, B: ?Sized + core::hash::Hash> core::hash::Hash for Pair<A, B> {
+    /// Hashes the values its components point to.
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.fst().hash(state);
+        self.snd().hash(state);
+    }
+}
+
 impl<'a, A: ?Sized, B: ?Sized> Pair<A, B> {
     /// The same as `pair_ref.into()`.
     pub fn new<'x:'a>( pair_ref: &'a (&'x A, &'x B) ) -> &'a Self {
@@ -7,8 +7,13 @@
 /// with the help of [`new`](#method.new) and [`new_mut`](#method.new_mut) 
 /// functions.
 ///
-/// The current version of the crate provides only a minimal viable interface: 
-/// the distributive laws and the [`modify`](#method.modify) method.
+/// Besides the distributive laws and the [`modify`](#method.modify) method,
+/// `Slice` also provides [`iter`](#method.iter) and [`iter_mut`](#method.iter_mut),
+/// which walk the wrapped references one at a time instead of materializing
+/// the whole `[&T]`/`[&mut T]` slice, and [`get`](#method.get),
+/// [`split_at`](#method.split_at), [`chunks`](#method.chunks) and
+/// [`windows`](#method.windows), which reinterpret sub-ranges of the
+/// wrapped references back into a `&Slice<T>` without copying.
 ///
 /// To get a concrete reference (or a sublice) out of the `Slice` 
 /// you can write
@@ -35,16 +40,58 @@
 ///     *real_slice[3] += 2; 
 /// });
 ///
-/// assert!(a == 4);
-/// assert!(b == 5);
-/// assert!(c == 6);
-/// assert!(d == 7);
+/// // Iterating is much less clumsy:
+/// slice.iter_mut().for_each(|r| *r += 1);
+///
+/// // `get`, `split_at`, `chunks` and `windows` reinterpret sub-ranges
+/// // back into a `&Slice<T>`/`&mut Slice<T>` instead of forcing a trip
+/// // through `as_ref()`/`as_mut()`:
+/// assert!(*slice.get(0).unwrap() == 5);
+///
+/// let (left, right) = slice.split_at(2);
+/// assert!(*left.get(1).unwrap() == 6);
+/// assert!(*right.get(1).unwrap() == 8);
+///
+/// let sums: Vec<i32> = slice.chunks(2).map(|pair| *pair.get(0).unwrap() + *pair.get(1).unwrap()).collect();
+/// assert!(sums == [11, 15]);
+///
+/// let diffs: Vec<i32> = slice.windows(2).map(|pair| *pair.get(1).unwrap() - *pair.get(0).unwrap()).collect();
+/// assert!(diffs == [1, 1, 1]);
+///
+/// assert!(a == 5);
+/// assert!(b == 6);
+/// assert!(c == 7);
+/// assert!(d == 8);
 /// ```
 ///
 /// Next versions of the crate are expected to provide
-/// an interface analogous to the one of standard slices (unfortunately, 
+/// an interface analogous to the one of standard slices (unfortunately,
 /// the lazy solution, i.e. implementing the `Deref` trait,
-/// can't be used, because of the necessary &-head of the type). 
+/// can't be used, because of the necessary &-head of the type).
+///
+/// `Slice<T>` also implements `PartialEq`/`Eq`/`PartialOrd`/`Ord`/`Hash`
+/// (whenever `T` does), comparing and hashing by the values the wrapped
+/// references point to rather than by address. This makes `&Slice<T>`
+/// usable as a `BTreeMap`/`HashMap` key:
+///
+/// ```
+/// # use multiref::Slice;
+/// # use std::collections::BTreeMap;
+/// let (a, b, c) = (1, 2, 3);
+/// let array1 = [&a, &b];
+/// let array2 = [&a, &c];
+/// let slice1 = Slice::new(&array1[..]);
+/// let slice2 = Slice::new(&array2[..]);
+///
+/// assert!(slice1 == slice1);
+/// assert!(slice1 < slice2);
+///
+/// let mut map = BTreeMap::new();
+/// map.insert(slice1, "one-two");
+/// map.insert(slice2, "one-three");
+/// assert!(map[slice1] == "one-two");
+/// assert!(map[slice2] == "one-three");
+/// ```
 #[repr(transparent)]
 pub struct Slice<T: ?Sized> {
     _slice: [*const T],
@@ -66,6 +113,38 @@ impl<'a, 'x: 'a, T> From<&'a mut [&'x mut T]> for &'a mut Slice<T> where
     }
 }
 
+impl<T: ?Sized + PartialEq> PartialEq for Slice<T> {
+    /// Compares the wrapped references by the values they point to.
+    fn eq(&self, other: &Self) -> bool {
+        self.as_ref() == other.as_ref()
+    }
+}
+
+impl<T: ?Sized + Eq> Eq for Slice<T> {}
+
+impl<T: ?Sized + PartialOrd> PartialOrd for Slice<T> {
+    /// Orders the wrapped references lexicographically by the values
+    /// they point to.
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.as_ref().partial_cmp(other.as_ref())
+    }
+}
+
+impl<T: ?Sized + Ord> Ord for Slice<T> {
+    /// Orders the wrapped references lexicographically by the values
+    /// they point to.
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_ref().cmp(other.as_ref())
+    }
+}
+
+impl<T: ?Sized + core::hash::Hash> core::hash::Hash for Slice<T> {
+    /// Hashes the values the wrapped references point to.
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_ref().hash(state)
+    }
+}
+
 impl<'a, T: ?Sized> Slice<T> {
     /// The same as `slice.into()`.
     pub fn new<'x:'a>( slice: &'a [&'x T] ) -> &'a Self {
@@ -93,4 +172,337 @@ impl<'a, T: ?Sized> Slice<T> {
     {
         f(self.as_mut())
     }
+
+    /// An iterator over the wrapped references.
+    ///
+    /// Unlike [`as_ref`](#method.as_ref) this doesn't need to materialize
+    /// the whole `[&T]` slice at once.
+    pub fn iter(&'a self) -> Iter<'a, T> {
+        Iter { inner: self._slice.iter() }
+    }
+
+    /// A mutable iterator over the wrapped references.
+    ///
+    /// Unlike [`as_mut`](#method.as_mut) this doesn't need to materialize
+    /// the whole `[&mut T]` slice at once.
+    pub fn iter_mut(&'a mut self) -> IterMut<'a, T> {
+        IterMut { inner: self._slice.iter_mut() }
+    }
+
+    /// The number of wrapped references.
+    pub fn len(&self) -> usize {
+        self._slice.len()
+    }
+
+    /// `true` if there are no wrapped references.
+    pub fn is_empty(&self) -> bool {
+        self._slice.is_empty()
+    }
+
+    /// Gets a single reference or a reinterpreted sub-`Slice`, analogous to
+    /// `core::slice::get`.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    pub fn get<I: SliceIndex<'a, T>>(&'a self, index: I) -> Option<I::Output> {
+        index.get(self)
+    }
+
+    /// The mutable version of [`get`](#method.get).
+    pub fn get_mut<I: SliceIndex<'a, T>>(&'a mut self, index: I) -> Option<I::OutputMut> {
+        index.get_mut(self)
+    }
+
+    /// Splits the wrapped references into two reinterpreted sub-`Slice`s
+    /// at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > self.len()`, same as `core::slice::split_at`.
+    pub fn split_at(&'a self, mid: usize) -> (&'a Slice<T>, &'a Slice<T>) {
+        let (left, right) = self._slice.split_at(mid);
+        unsafe { (&*(left as *const _ as *const _), &*(right as *const _ as *const _)) }
+    }
+
+    /// The mutable version of [`split_at`](#method.split_at).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > self.len()`, same as `core::slice::split_at_mut`.
+    pub fn split_at_mut(&'a mut self, mid: usize) -> (&'a mut Slice<T>, &'a mut Slice<T>) {
+        let (left, right) = self._slice.split_at_mut(mid);
+        unsafe { (&mut *(left as *mut _ as *mut _), &mut *(right as *mut _ as *mut _)) }
+    }
+
+    /// An iterator over non-overlapping reinterpreted sub-`Slice`s of
+    /// length `chunk_size`, with the last one possibly shorter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is `0`, same as `core::slice::chunks`.
+    pub fn chunks(&'a self, chunk_size: usize) -> Chunks<'a, T> {
+        Chunks { inner: self._slice.chunks(chunk_size) }
+    }
+
+    /// An iterator over overlapping reinterpreted sub-`Slice`s of
+    /// length `size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is `0`, same as `core::slice::windows`.
+    pub fn windows(&'a self, size: usize) -> Windows<'a, T> {
+        Windows { inner: self._slice.windows(size) }
+    }
+
+    /// Slides a window of width `N` across the wrapped references, calling
+    /// `f` once per window (in continuation-passing style, so no `[&T; N]`
+    /// ever needs to be materialized).
+    ///
+    /// For a `Slice` of length `L` this yields `L.saturating_sub(N - 1)`
+    /// results, in order; it yields nothing if `L < N`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is `0`.
+    ///
+    /// ```
+    /// # use multiref::Slice;
+    /// let (a, b, c, d) = (1, 3, 6, 10);
+    /// let array = [&a, &b, &c, &d];
+    /// let slice = Slice::new(&array[..]);
+    ///
+    /// // adjacent diffs, without ever materializing `[&i32; 2]`:
+    /// let diffs: Vec<i32> = slice
+    ///     .map_windows::<2, _>(|w| *w.get(1).unwrap() - *w.get(0).unwrap())
+    ///     .collect();
+    /// assert!(diffs == [2, 3, 4]);
+    ///
+    /// let (mut a, mut b, mut c, mut d) = (1, 3, 6, 10);
+    /// let mut array = [&mut a, &mut b, &mut c, &mut d];
+    /// let mut slice = Slice::new_mut(&mut array[..]);
+    ///
+    /// // overwrite each element (but the last) with the diff to its successor:
+    /// slice.map_windows_mut::<2, _>(|w| {
+    ///     let diff = *w.get(1).unwrap() - *w.get(0).unwrap();
+    ///     *w.get_mut(0).unwrap() = diff;
+    /// }).for_each(drop);
+    ///
+    /// assert!(a == 2);
+    /// assert!(b == 3);
+    /// assert!(c == 4);
+    /// assert!(d == 10);
+    /// ```
+    pub fn map_windows<const N: usize, R>(
+        &'a self,
+        mut f: impl FnMut(&Slice<T>) -> R + 'a,
+    ) -> impl Iterator<Item = R> + 'a {
+        assert!(N != 0, "window size must be non-zero");
+
+        let count = self._slice.len().saturating_sub(N - 1);
+
+        (0 .. count).map(move |i| {
+            let window = &self._slice[i .. i + N];
+            f(unsafe { &*(window as *const _ as *const _) })
+        })
+    }
+
+    /// The mutable version of [`map_windows`](#method.map_windows).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is `0`.
+    pub fn map_windows_mut<const N: usize, R>(
+        &'a mut self,
+        mut f: impl FnMut(&mut Slice<T>) -> R + 'a,
+    ) -> impl Iterator<Item = R> + 'a {
+        assert!(N != 0, "window size must be non-zero");
+
+        let count = self._slice.len().saturating_sub(N - 1);
+        let ptr = self._slice.as_mut_ptr();
+
+        (0 .. count).map(move |i| {
+            let window = unsafe { core::slice::from_raw_parts_mut(ptr.add(i), N) };
+            f(unsafe { &mut *(window as *mut _ as *mut _) })
+        })
+    }
+}
+
+/// Helper trait powering the overloaded [`Slice::get`](struct.Slice.html#method.get)
+/// and [`Slice::get_mut`](struct.Slice.html#method.get_mut) methods,
+/// analogous to `core::slice::SliceIndex`.
+///
+/// Implemented for `usize` (yielding a single reference) and for the
+/// standard range types (yielding a reinterpreted sub-`Slice`).
+pub trait SliceIndex<'a, T: ?Sized + 'a> {
+    /// The result of [`Slice::get`](struct.Slice.html#method.get).
+    type Output;
+    /// The result of [`Slice::get_mut`](struct.Slice.html#method.get_mut).
+    type OutputMut;
+
+    #[doc(hidden)]
+    fn get(self, slice: &'a Slice<T>) -> Option<Self::Output>;
+    #[doc(hidden)]
+    fn get_mut(self, slice: &'a mut Slice<T>) -> Option<Self::OutputMut>;
+}
+
+impl<'a, T: ?Sized + 'a> SliceIndex<'a, T> for usize {
+    type Output = &'a T;
+    type OutputMut = &'a mut T;
+
+    fn get(self, slice: &'a Slice<T>) -> Option<Self::Output> {
+        slice._slice.get(self).map(|&ptr| unsafe { &*ptr })
+    }
+
+    fn get_mut(self, slice: &'a mut Slice<T>) -> Option<Self::OutputMut> {
+        slice._slice.get_mut(self).map(|&mut ptr| unsafe { &mut *(ptr as *mut T) })
+    }
+}
+
+macro_rules! impl_range_slice_index {
+    ($($range:ty),* $(,)?) => { $(
+        impl<'a, T: ?Sized + 'a> SliceIndex<'a, T> for $range {
+            type Output = &'a Slice<T>;
+            type OutputMut = &'a mut Slice<T>;
+
+            fn get(self, slice: &'a Slice<T>) -> Option<Self::Output> {
+                slice._slice.get(self).map(|s| unsafe { &*(s as *const _ as *const _) })
+            }
+
+            fn get_mut(self, slice: &'a mut Slice<T>) -> Option<Self::OutputMut> {
+                slice._slice.get_mut(self).map(|s| unsafe { &mut *(s as *mut _ as *mut _) })
+            }
+        }
+    )* }
+}
+
+impl_range_slice_index!(
+    core::ops::Range<usize>,
+    core::ops::RangeFrom<usize>,
+    core::ops::RangeTo<usize>,
+    core::ops::RangeFull,
+    core::ops::RangeInclusive<usize>,
+    core::ops::RangeToInclusive<usize>,
+);
+
+/// An iterator over non-overlapping chunks of a [`Slice`](struct.Slice.html).
+///
+/// Created by the [`Slice::chunks`](struct.Slice.html#method.chunks) method.
+pub struct Chunks<'a, T: ?Sized> {
+    inner: core::slice::Chunks<'a, *const T>,
+}
+
+impl<'a, T: ?Sized> Iterator for Chunks<'a, T> {
+    type Item = &'a Slice<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|s| unsafe { &*(s as *const _ as *const _) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, T: ?Sized> DoubleEndedIterator for Chunks<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|s| unsafe { &*(s as *const _ as *const _) })
+    }
+}
+
+impl<'a, T: ?Sized> ExactSizeIterator for Chunks<'a, T> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+/// An iterator over overlapping windows of a [`Slice`](struct.Slice.html).
+///
+/// Created by the [`Slice::windows`](struct.Slice.html#method.windows) method.
+pub struct Windows<'a, T: ?Sized> {
+    inner: core::slice::Windows<'a, *const T>,
+}
+
+impl<'a, T: ?Sized> Iterator for Windows<'a, T> {
+    type Item = &'a Slice<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|s| unsafe { &*(s as *const _ as *const _) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, T: ?Sized> DoubleEndedIterator for Windows<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|s| unsafe { &*(s as *const _ as *const _) })
+    }
+}
+
+impl<'a, T: ?Sized> ExactSizeIterator for Windows<'a, T> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+/// An iterator over the references wrapped by a [`Slice`](struct.Slice.html).
+///
+/// Created by the [`Slice::iter`](struct.Slice.html#method.iter) method.
+pub struct Iter<'a, T: ?Sized> {
+    inner: core::slice::Iter<'a, *const T>,
+}
+
+impl<'a, T: ?Sized> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|&ptr| unsafe { &*ptr })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, T: ?Sized> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|&ptr| unsafe { &*ptr })
+    }
+}
+
+impl<'a, T: ?Sized> ExactSizeIterator for Iter<'a, T> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+/// A mutable iterator over the references wrapped by a [`Slice`](struct.Slice.html).
+///
+/// Created by the [`Slice::iter_mut`](struct.Slice.html#method.iter_mut) method.
+pub struct IterMut<'a, T: ?Sized> {
+    inner: core::slice::IterMut<'a, *const T>,
+}
+
+impl<'a, T: ?Sized> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|ptr| unsafe { &mut *(*ptr as *mut T) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, T: ?Sized> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|ptr| unsafe { &mut *(*ptr as *mut T) })
+    }
+}
+
+impl<'a, T: ?Sized> ExactSizeIterator for IterMut<'a, T> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
 }